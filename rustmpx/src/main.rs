@@ -5,7 +5,11 @@ use std::time::{Duration, Instant};
 
 // Web + async
 #[cfg(feature = "web")]
-use axum::{routing::{get, post}, Router, extract::{Multipart, State}, response::{Html, Redirect}, Json, http::StatusCode};
+use axum::{routing::{get, post}, Router, extract::{Multipart, State}, response::{Html, Redirect, sse::{Event, Sse}}, Json, http::StatusCode};
+#[cfg(feature = "web")]
+use futures::StreamExt;
+#[cfg(feature = "web")]
+use tokio_stream::wrappers::BroadcastStream;
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
 
@@ -64,6 +68,8 @@ enum Commands {
 		rds2: f64,
 		#[arg(long)]
 		enable_rds2: bool,
+		#[arg(long, default_value_t = -23.0)]
+		loudness_target: f64,
 	},
 	/// List audio output devices
 	Devices {
@@ -76,8 +82,31 @@ enum Commands {
 		fs: u32,
 		#[arg(long)]
 		device_index: Option<usize>,
+		/// Output target: "device" (local sound card) or "network"
+		#[arg(long, default_value = "device")]
+		sink: String,
+		/// Remote address for --sink=network, e.g. 192.168.1.50:9000
+		#[arg(long)]
+		sink_addr: Option<String>,
+		/// Network transport for --sink=network: udp, tcp, or quic
+		#[arg(long, default_value = "udp")]
+		sink_transport: String,
+		/// Expected SHA-256 fingerprint (hex) of the QUIC sink's certificate; when set, the
+		/// connection is rejected unless the presented cert matches. Leave unset only for a
+		/// trusted local link -- without it, TLS verification is skipped entirely.
+		#[arg(long)]
+		sink_quic_cert_sha256: Option<String>,
 		#[arg(long)]
 		input_file: Option<String>,
+		/// Stream a remote HTTP(S) URL instead of a local file or tone
+		#[arg(long)]
+		url: Option<String>,
+		/// Comma-separated list of local audio files to play as a playlist
+		#[arg(long)]
+		playlist: Option<String>,
+		/// What to do once the playlist reaches the end: loop or stop
+		#[arg(long, default_value = "loop")]
+		playlist_mode: String,
 		#[arg(long, default_value_t = 1000.0)]
 		tone: f64,
 		#[arg(long, default_value_t = 0x1234)]
@@ -94,6 +123,8 @@ enum Commands {
 		rds2: f64,
 		#[arg(long)]
 		enable_rds2: bool,
+		#[arg(long, default_value_t = -23.0)]
+		loudness_target: f64,
 	},
 	/// Run the web UI
 	Serve {
@@ -254,6 +285,7 @@ struct RdsGen {
 impl RdsGen {
 	fn new(cfg: RdsConfig) -> Self { Self { cfg, ps_idx: 0, rt_idx: 0, logo_bits: None, logo_pos: 0 } }
 	fn set_logo_bits(&mut self, bits: Option<Vec<u8>>) { self.logo_bits = bits; self.logo_pos = 0; }
+	fn set_ps_rt(&mut self, ps: String, rt: String) { self.cfg.ps = ps; self.cfg.rt = rt; self.ps_idx = 0; self.rt_idx = 0; }
 	fn next_logo_chunk(&mut self, max_bits: usize) -> Option<Vec<u8>> {
 		if let Some(data) = &self.logo_bits {
 			if data.is_empty() { return None; }
@@ -347,8 +379,189 @@ fn bpsk_subcarrier(bits: &[u8], fs: u32, sub_hz: f64, bitrate: f64) -> Vec<f32>
 	out
 }
 
-fn make_mpx(left: &[f32], right: &[f32], fs: u32, pilot: f64, rds: f64, rds2: f64, rds_bits: &[u8], enable_rds2: bool) -> Vec<f32> {
+// ============ Loudness processing (ITU-R BS.1770 / EBU R128) ============
+const LOUDNESS_BLOCK_MS: f64 = 400.0;
+const LOUDNESS_OVERLAP: f64 = 0.75;
+const LOUDNESS_ABSOLUTE_GATE_LUFS: f64 = -70.0;
+const LOUDNESS_RELATIVE_GATE_LU: f64 = -10.0;
+const TRUE_PEAK_OVERSAMPLE: usize = 4;
+const TRUE_PEAK_CEILING_DB: f64 = -1.0;
+const GAIN_SLEW_DB_PER_SEC: f64 = 6.0;
+// Rolling window for the integrated-loudness measurement, in blocks (~100 ms hop each, so
+// ~90 s of history) -- bounds memory and the per-block recompute cost for an unattended
+// station that may run for hours or days.
+const LOUDNESS_MAX_BLOCKS: usize = 900;
+pub const DEFAULT_LOUDNESS_TARGET_LUFS: f64 = -23.0;
+
+#[derive(Clone, Copy)]
+struct Biquad { b0: f64, b1: f64, b2: f64, a1: f64, a2: f64, z1: f64, z2: f64 }
+
+impl Biquad {
+	fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self { Self { b0, b1, b2, a1, a2, z1: 0.0, z2: 0.0 } }
+	fn process(&mut self, x: f64) -> f64 {
+		let y = self.b0 * x + self.z1;
+		self.z1 = self.b1 * x - self.a1 * y + self.z2;
+		self.z2 = self.b2 * x - self.a2 * y;
+		y
+	}
+}
+
+// K-weighting stage 1: +4 dB high-shelf above ~1.5 kHz (BS.1770 "pre-filter")
+fn k_prefilter(fs: f64) -> Biquad {
+	let gain_db = 4.0;
+	let fc = 1500.0;
+	let q = 1.0 / 2f64.sqrt();
+	let a = 10f64.powf(gain_db / 40.0);
+	let w0 = 2.0 * PI * fc / fs;
+	let alpha = w0.sin() / (2.0 * q);
+	let cos_w0 = w0.cos();
+	let sqrt_a = a.sqrt();
+	let b0 = a * ((a + 1.0) + (a - 1.0) * cos_w0 + 2.0 * sqrt_a * alpha);
+	let b1 = -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0);
+	let b2 = a * ((a + 1.0) + (a - 1.0) * cos_w0 - 2.0 * sqrt_a * alpha);
+	let a0 = (a + 1.0) - (a - 1.0) * cos_w0 + 2.0 * sqrt_a * alpha;
+	let a1 = 2.0 * ((a - 1.0) - (a + 1.0) * cos_w0);
+	let a2 = (a + 1.0) - (a - 1.0) * cos_w0 - 2.0 * sqrt_a * alpha;
+	Biquad::new(b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
+}
+
+// K-weighting stage 2: RLB high-pass around 38 Hz (BS.1770 "RLB weighting")
+fn k_rlb_filter(fs: f64) -> Biquad {
+	let fc = 38.0;
+	let q = 0.5;
+	let w0 = 2.0 * PI * fc / fs;
+	let alpha = w0.sin() / (2.0 * q);
+	let cos_w0 = w0.cos();
+	let b0 = (1.0 + cos_w0) / 2.0;
+	let b1 = -(1.0 + cos_w0);
+	let b2 = (1.0 + cos_w0) / 2.0;
+	let a0 = 1.0 + alpha;
+	let a1 = -2.0 * cos_w0;
+	let a2 = 1.0 - alpha;
+	Biquad::new(b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
+}
+
+/// Streaming ITU-R BS.1770 / EBU R128 loudness normalizer with a look-ahead true-peak
+/// limiter. Keeps filter, gating and gain state across calls so it can run block-by-block
+/// on a live stream instead of requiring the whole program in memory up front.
+struct AudioProcessor {
+	fs: f64,
+	target_lufs: f64,
+	pre_l: Biquad, rlb_l: Biquad,
+	pre_r: Biquad, rlb_r: Biquad,
+	block_len: usize,
+	hop_len: usize,
+	block_window_l: Vec<f64>,
+	block_window_r: Vec<f64>,
+	block_loudnesses: Vec<f64>,
+	current_gain_db: f64,
+	cached_integrated_lufs: Option<f64>,
+}
+
+impl AudioProcessor {
+	fn new(fs: u32, target_lufs: f64) -> Self {
+		let fs_f = fs as f64;
+		let block_len = (fs_f * LOUDNESS_BLOCK_MS / 1000.0) as usize;
+		let hop_len = ((block_len as f64) * (1.0 - LOUDNESS_OVERLAP)).max(1.0) as usize;
+		Self {
+			fs: fs_f, target_lufs,
+			pre_l: k_prefilter(fs_f), rlb_l: k_rlb_filter(fs_f),
+			pre_r: k_prefilter(fs_f), rlb_r: k_rlb_filter(fs_f),
+			block_len, hop_len,
+			block_window_l: Vec::with_capacity(block_len),
+			block_window_r: Vec::with_capacity(block_len),
+			block_loudnesses: Vec::new(),
+			current_gain_db: 0.0,
+			cached_integrated_lufs: None,
+		}
+	}
+
+	fn block_loudness(ms_l: f64, ms_r: f64) -> f64 {
+		-0.691 + 10.0 * (ms_l + ms_r).max(1e-12).log10()
+	}
+
+	// Feeds freshly K-weighted mean-square energy into the 400 ms / 75%-overlap measurement
+	// window, emitting a gated block loudness each time a hop's worth of new samples arrives.
+	fn measure(&mut self, kw_l: f64, kw_r: f64) {
+		self.block_window_l.push(kw_l * kw_l);
+		self.block_window_r.push(kw_r * kw_r);
+		if self.block_window_l.len() >= self.block_len {
+			let ms_l: f64 = self.block_window_l.iter().sum::<f64>() / self.block_len as f64;
+			let ms_r: f64 = self.block_window_r.iter().sum::<f64>() / self.block_len as f64;
+			let loudness = Self::block_loudness(ms_l, ms_r);
+			if loudness > LOUDNESS_ABSOLUTE_GATE_LUFS { self.block_loudnesses.push(loudness); }
+			if self.block_loudnesses.len() > LOUDNESS_MAX_BLOCKS {
+				let excess = self.block_loudnesses.len() - LOUDNESS_MAX_BLOCKS;
+				self.block_loudnesses.drain(0..excess);
+			}
+			let drain = self.hop_len.min(self.block_window_l.len());
+			self.block_window_l.drain(0..drain);
+			self.block_window_r.drain(0..drain);
+			self.cached_integrated_lufs = self.integrated_loudness();
+		}
+	}
+
+	fn integrated_loudness(&self) -> Option<f64> {
+		if self.block_loudnesses.is_empty() { return None; }
+		let ungated_mean: f64 = self.block_loudnesses.iter().sum::<f64>() / self.block_loudnesses.len() as f64;
+		let relative_gate = ungated_mean + LOUDNESS_RELATIVE_GATE_LU;
+		let gated: Vec<f64> = self.block_loudnesses.iter().copied().filter(|&l| l > relative_gate).collect();
+		if gated.is_empty() { Some(ungated_mean) } else { Some(gated.iter().sum::<f64>() / gated.len() as f64) }
+	}
+
+	// Slew-limits the gain envelope so normalization can run sample-by-sample on a live
+	// stream without zipper noise, then runs a look-ahead true-peak limiter over the result.
+	fn apply(&mut self, left: &mut [f32], right: &mut [f32]) {
+		let max_step_db = GAIN_SLEW_DB_PER_SEC / self.fs;
+		for i in 0..left.len() {
+			let kw_l = self.rlb_l.process(self.pre_l.process(left[i] as f64));
+			let kw_r = self.rlb_r.process(self.pre_r.process(right[i] as f64));
+			self.measure(kw_l, kw_r);
+			let target_gain_db = match self.cached_integrated_lufs {
+				Some(lufs) => self.target_lufs - lufs,
+				None => self.current_gain_db,
+			};
+			let delta = (target_gain_db - self.current_gain_db).clamp(-max_step_db, max_step_db);
+			self.current_gain_db += delta;
+			let gain = db_to_linear(self.current_gain_db) as f32;
+			left[i] *= gain;
+			right[i] *= gain;
+		}
+		Self::true_peak_limit(left);
+		Self::true_peak_limit(right);
+	}
+
+	// 4x-oversampled look-ahead true-peak limiter: estimates inter-sample peaks and scales
+	// the whole block down if any of them would exceed the ceiling.
+	fn true_peak_limit(samples: &mut [f32]) {
+		if samples.len() < 2 { return; }
+		let ceiling = db_to_linear(TRUE_PEAK_CEILING_DB) as f32;
+		let mut oversampled = Vec::with_capacity(samples.len() * TRUE_PEAK_OVERSAMPLE);
+		let last_idx = samples.len() - 1;
+		for i in 0..last_idx {
+			let (p0, p1, p2, p3) = (samples[i.saturating_sub(1)], samples[i], samples[i + 1], samples[(i + 2).min(last_idx)]);
+			oversampled.push(p1);
+			for k in 1..TRUE_PEAK_OVERSAMPLE {
+				let t = k as f32 / TRUE_PEAK_OVERSAMPLE as f32;
+				// Catmull-Rom cubic: unlike a straight line it can overshoot p1/p2, which is
+				// exactly the inter-sample peak being looked for.
+				oversampled.push(0.5 * (2.0 * p1 + (p2 - p0) * t + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t * t));
+			}
+		}
+		if let Some(&last) = samples.last() { oversampled.push(last); }
+		let true_peak = oversampled.iter().fold(0.0f32, |m, &s| m.max(s.abs()));
+		if true_peak > ceiling {
+			let scale = ceiling / true_peak;
+			for s in samples.iter_mut() { *s *= scale; }
+		}
+	}
+}
+
+fn make_mpx(left: &[f32], right: &[f32], fs: u32, pilot: f64, rds: f64, rds2: f64, rds_bits: &[u8], enable_rds2: bool, processor: &mut AudioProcessor) -> Vec<f32> {
 	assert_eq!(left.len(), right.len());
+	let mut left = left.to_vec();
+	let mut right = right.to_vec();
+	processor.apply(&mut left, &mut right);
 	let n = left.len();
 	let mut out = Vec::with_capacity(n);
 	let mut lpr = vec![0.0f32; n];
@@ -413,7 +626,7 @@ fn list_output_devices() -> Vec<OutputDeviceInfo> { vec![] }
 #[derive(Clone)]
 struct StreamConfig {
 	fs: u32,
-	device_index: Option<usize>,
+	sink: SinkKind,
 	source: SourceKind,
 	pi: u16,
 	ps: String,
@@ -423,19 +636,643 @@ struct StreamConfig {
 	rds2: f64,
 	enable_rds2: bool,
 	logo_bits: Option<Vec<u8>>,
+	loudness_target: f64,
 }
 #[derive(Clone)]
-enum SourceKind { Tone { freq: f64 }, File { path: String } }
+enum SourceKind { Tone { freq: f64 }, File { path: String }, Url { url: String }, Playlist { items: Vec<PlaylistItem>, mode: PlaylistMode } }
+
+// A single playlist entry. When a track with `ps` or `rt` set starts, both replace the current
+// RDS text (an unset one becomes empty), so a station can tag individual items (e.g. a liner or
+// an ad) without restarting. The text is not restored when the track ends: it stays until a
+// later track sets its own.
+#[derive(Clone, Serialize, Deserialize)]
+struct PlaylistItem { path: String, ps: Option<String>, rt: Option<String> }
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum PlaylistMode { Loop, Stop }
+
+// Live playlist state shared between the streaming task and the `/queue`, `/skip` web
+// handlers, so reordering or skipping takes effect immediately instead of on next start.
+struct PlaylistState {
+	items: Vec<PlaylistItem>,
+	index: usize,
+	mode: PlaylistMode,
+	skip_requested: bool,
+}
+
+impl PlaylistState {
+	fn new(items: Vec<PlaylistItem>, mode: PlaylistMode) -> Self {
+		Self { items, index: 0, mode, skip_requested: false }
+	}
+}
+
+type SharedPlaylist = Arc<Mutex<PlaylistState>>;
+
+// Where the generated mono MPX baseband stream ends up: a local sound card, or a remote
+// SDR/transmitter reachable over the network. Separating the two lets DSP generation run
+// on different hardware than the RF stage.
+#[derive(Clone)]
+enum SinkKind {
+	Device { index: Option<usize> },
+	// `quic_cert_sha256` pins the expected SHA-256 fingerprint of the remote QUIC endpoint's
+	// certificate (hex-encoded); `None` falls back to accepting any certificate, since the
+	// remote is typically an operator-controlled box with no CA-issued cert.
+	Network { addr: String, transport: NetworkTransport, quic_cert_sha256: Option<String> },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NetworkTransport { Udp, Tcp, Quic }
+
+impl NetworkTransport {
+	fn parse(s: &str) -> Option<Self> {
+		match s.to_ascii_lowercase().as_str() {
+			"udp" => Some(Self::Udp),
+			"tcp" => Some(Self::Tcp),
+			"quic" => Some(Self::Quic),
+			_ => None,
+		}
+	}
+}
+
+// Fixed-duration baseband frame tagged with a monotonically increasing sequence number and
+// the sample rate it was generated at, so a remote receiver can detect drops and resync.
+const BASEBAND_FRAME_SAMPLES: usize = 4096;
+const BASEBAND_FRAME_QUEUE_LEN: usize = 8;
+
+struct BasebandFrame { seq: u64, sample_rate: u32, samples: Vec<f32> }
+
+impl BasebandFrame {
+	fn encode(&self) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(8 + 4 + 4 + self.samples.len() * 4);
+		buf.extend_from_slice(&self.seq.to_le_bytes());
+		buf.extend_from_slice(&self.sample_rate.to_le_bytes());
+		buf.extend_from_slice(&(self.samples.len() as u32).to_le_bytes());
+		for &s in &self.samples { buf.extend_from_slice(&s.to_le_bytes()); }
+		buf
+	}
+}
+
+// Shared handoff between the DSP loop and the network sender task. When the link is
+// congested the DSP loop drops the oldest queued frame rather than blocking or growing the
+// queue without bound — stale baseband audio is worse than a dropout for live FM.
+#[derive(Default)]
+struct BasebandQueue { frames: std::collections::VecDeque<BasebandFrame> }
+type SharedBasebandQueue = Arc<Mutex<BasebandQueue>>;
+
+fn queue_frame_drop_oldest(queue: &SharedBasebandQueue, frame: BasebandFrame) {
+	let mut q = queue.lock().unwrap();
+	if q.frames.len() >= BASEBAND_FRAME_QUEUE_LEN { q.frames.pop_front(); }
+	q.frames.push_back(frame);
+}
+
+// The remote end is typically an operator-controlled SDR/transmitter box with no CA-issued
+// cert, so verification is skipped by default -- but this sink carries the live broadcast
+// feed, so an operator who cares can pin the expected certificate's SHA-256 fingerprint via
+// `quic_cert_sha256` instead of trusting whatever presents itself.
+#[cfg(feature = "audio")]
+#[derive(Debug)]
+struct SkipServerVerification;
+
+#[cfg(feature = "audio")]
+impl rustls::client::ServerCertVerifier for SkipServerVerification {
+	fn verify_server_cert(
+		&self,
+		_end_entity: &rustls::Certificate,
+		_intermediates: &[rustls::Certificate],
+		_server_name: &rustls::ServerName,
+		_scts: &mut dyn Iterator<Item = &[u8]>,
+		_ocsp_response: &[u8],
+		_now: std::time::SystemTime,
+	) -> Result<rustls::client::ServerCertVerified, rustls::Error> {
+		Ok(rustls::client::ServerCertVerified::assertion())
+	}
+}
+
+#[cfg(feature = "audio")]
+#[derive(Debug)]
+struct PinnedServerVerification { expected_sha256: Vec<u8> }
+
+#[cfg(feature = "audio")]
+impl rustls::client::ServerCertVerifier for PinnedServerVerification {
+	fn verify_server_cert(
+		&self,
+		end_entity: &rustls::Certificate,
+		_intermediates: &[rustls::Certificate],
+		_server_name: &rustls::ServerName,
+		_scts: &mut dyn Iterator<Item = &[u8]>,
+		_ocsp_response: &[u8],
+		_now: std::time::SystemTime,
+	) -> Result<rustls::client::ServerCertVerified, rustls::Error> {
+		let actual = ring::digest::digest(&ring::digest::SHA256, &end_entity.0);
+		if actual.as_ref() == self.expected_sha256.as_slice() {
+			Ok(rustls::client::ServerCertVerified::assertion())
+		} else {
+			Err(rustls::Error::General("QUIC peer certificate does not match the pinned fingerprint".into()))
+		}
+	}
+}
+
+#[cfg(feature = "audio")]
+fn decode_hex(s: &str) -> anyhow::Result<Vec<u8>> {
+	let s = s.trim();
+	if s.len() % 2 != 0 { anyhow::bail!("hex fingerprint must have an even number of digits"); }
+	(0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| anyhow::anyhow!(e))).collect()
+}
+
+#[cfg(feature = "audio")]
+fn quic_client_config(cert_sha256_hex: Option<&str>) -> anyhow::Result<quinn::ClientConfig> {
+	let verifier: Arc<dyn rustls::client::ServerCertVerifier> = match cert_sha256_hex {
+		Some(hex) => Arc::new(PinnedServerVerification { expected_sha256: decode_hex(hex)? }),
+		None => Arc::new(SkipServerVerification),
+	};
+	let crypto = rustls::ClientConfig::builder()
+		.with_safe_defaults()
+		.with_custom_certificate_verifier(verifier)
+		.with_no_client_auth();
+	Ok(quinn::ClientConfig::new(Arc::new(crypto)))
+}
+
+#[cfg(feature = "audio")]
+async fn run_network_sink(addr: String, transport: NetworkTransport, quic_cert_sha256: Option<String>, queue: SharedBasebandQueue, stop: Arc<AtomicBool>) -> anyhow::Result<()> {
+	match transport {
+		NetworkTransport::Udp => {
+			let socket = tokio::net::UdpSocket::bind("0.0.0.0:0").await?;
+			socket.connect(&addr).await?;
+			while !stop.load(Ordering::SeqCst) {
+				let frame = queue.lock().unwrap().frames.pop_front();
+				match frame {
+					Some(f) => { let _ = socket.send(&f.encode()).await; }
+					None => tokio::time::sleep(Duration::from_millis(5)).await,
+				}
+			}
+		}
+		NetworkTransport::Tcp => {
+			use tokio::io::AsyncWriteExt;
+			let mut stream = tokio::net::TcpStream::connect(&addr).await?;
+			while !stop.load(Ordering::SeqCst) {
+				let frame = queue.lock().unwrap().frames.pop_front();
+				match frame {
+					Some(f) => {
+						let bytes = f.encode();
+						stream.write_all(&(bytes.len() as u32).to_le_bytes()).await?;
+						stream.write_all(&bytes).await?;
+					}
+					None => tokio::time::sleep(Duration::from_millis(5)).await,
+				}
+			}
+		}
+		NetworkTransport::Quic => {
+			// Each frame is sent as its own unreliable datagram so a late frame is dropped by
+			// the transport instead of head-of-line blocking newer ones.
+			let mut endpoint = quinn::Endpoint::client("0.0.0.0:0".parse()?)?;
+			endpoint.set_default_client_config(quic_client_config(quic_cert_sha256.as_deref())?);
+			let remote = tokio::net::lookup_host(&addr).await?.next().ok_or_else(|| anyhow::anyhow!("could not resolve {}", addr))?;
+			let server_name = remote.ip().to_string();
+			let connection = endpoint.connect(remote, &server_name)?.await?;
+			while !stop.load(Ordering::SeqCst) {
+				let frame = queue.lock().unwrap().frames.pop_front();
+				match frame {
+					Some(f) => { let _ = connection.send_datagram(f.encode().into()); }
+					None => tokio::time::sleep(Duration::from_millis(5)).await,
+				}
+			}
+		}
+	}
+	Ok(())
+}
+
+// A rolling activity feed (stream start/stop, buffer underruns, device errors, playlist
+// transitions) broadcast to every subscribed `/events` SSE client. `None` in CLI mode, where
+// there's no web client to receive it and status already goes to stdout.
+type EventSender = tokio::sync::broadcast::Sender<String>;
+
+fn emit_event(events: &Option<EventSender>, msg: impl Into<String>) {
+	if let Some(tx) = events { let _ = tx.send(msg.into()); }
+}
 
 struct RuntimeState {
 	stop_flag: Arc<AtomicBool>,
 	bg_task: Option<JoinHandle<()>>,
 	current_cfg: Option<StreamConfig>,
 	started_at: Option<std::time::Instant>,
+	url_health: Option<Arc<Mutex<UrlBufferHealth>>>,
+	playlist: Option<SharedPlaylist>,
+	events: Option<EventSender>,
 }
 
 impl RuntimeState {
-	fn new() -> Self { Self { stop_flag: Arc::new(AtomicBool::new(false)), bg_task: None, current_cfg: None, started_at: None } }
+	fn new() -> Self { Self { stop_flag: Arc::new(AtomicBool::new(false)), bg_task: None, current_cfg: None, started_at: None, url_health: None, playlist: None, events: None } }
+}
+
+// ============ Streaming HTTP/URL audio source ============
+const URL_RING_CAPACITY_SECONDS: f64 = 4.0;
+const URL_RANGE_CHUNK_BYTES: u64 = 256 * 1024;
+const URL_PREFETCH_AHEAD_BYTES: u64 = 2 * 1024 * 1024;
+const URL_MAX_RETRIES: u32 = 5;
+// A relay that ignores the Range header and serves an unbounded chunked body would otherwise
+// wedge the decode thread in `r.bytes()` forever, so every request gets a hard ceiling.
+const URL_HTTP_TIMEOUT_SECS: u64 = 15;
+
+/// Buffer health for a streaming `SourceKind::Url`, surfaced over `/status` so the web UI
+/// can show underruns instead of silently looping in silence.
+#[derive(Default, Clone, Copy)]
+struct UrlBufferHealth { buffered_samples: usize, downloaded_bytes: u64, total_bytes: Option<u64>, underruns: u64 }
+
+// Seek-aware HTTP range reader: issues range requests ahead of the read cursor to keep a
+// "high-water" download position in front of playback, and re-requests the missing byte
+// range on transient errors rather than aborting the stream.
+struct HttpRangeSource {
+	client: reqwest::blocking::Client,
+	url: String,
+	pos: u64,
+	buf: Vec<u8>,
+	buf_start: u64,
+	high_water: u64,
+	total_len: Option<u64>,
+	health: Arc<Mutex<UrlBufferHealth>>,
+}
+
+impl HttpRangeSource {
+	fn new(url: String, health: Arc<Mutex<UrlBufferHealth>>) -> anyhow::Result<Self> {
+		let client = reqwest::blocking::Client::builder()
+			.timeout(Duration::from_secs(URL_HTTP_TIMEOUT_SECS))
+			.build()?;
+		let total_len = client.head(&url).send().ok()
+			.and_then(|r| r.headers().get(reqwest::header::CONTENT_LENGTH).cloned())
+			.and_then(|v| v.to_str().ok().and_then(|s| s.parse::<u64>().ok()));
+		health.lock().unwrap().total_bytes = total_len;
+		Ok(Self { client, url, pos: 0, buf: Vec::new(), buf_start: 0, high_water: 0, total_len, health })
+	}
+
+	// Downloads chunks until the high-water mark reaches `upto`, retrying a failed range a
+	// few times before giving up rather than tearing down the whole stream on one blip.
+	fn ensure_filled(&mut self, upto: u64) -> anyhow::Result<()> {
+		while self.high_water < upto && self.total_len.map_or(true, |len| self.high_water < len) {
+			let start = self.high_water;
+			let end = start + URL_RANGE_CHUNK_BYTES - 1;
+			let mut attempt = 0;
+			loop {
+				let range = format!("bytes={}-{}", start, end);
+				let resp = self.client.get(&self.url).header(reqwest::header::RANGE, range).send();
+				let body = match resp {
+					// A server that ignores Range and answers 200 (or a 206 with no Content-Range)
+					// would hand back its entire, possibly-unbounded body here -- reject it like any
+					// other failed fetch instead of blocking forever buffering a live stream.
+					// A body that fails mid-read is retried the same way.
+					Ok(r) if r.status() == reqwest::StatusCode::PARTIAL_CONTENT && r.headers().contains_key(reqwest::header::CONTENT_RANGE) => r.bytes().ok(),
+					_ => None,
+				};
+				match body {
+					Some(bytes) => {
+						if bytes.is_empty() { self.total_len = Some(self.high_water); break; }
+						self.buf.extend_from_slice(&bytes);
+						self.high_water += bytes.len() as u64;
+						self.health.lock().unwrap().downloaded_bytes = self.high_water;
+						break;
+					}
+					None => {
+						attempt += 1;
+						if attempt >= URL_MAX_RETRIES { anyhow::bail!("failed to fetch bytes {}-{} of {}", start, end, self.url); }
+						std::thread::sleep(Duration::from_millis(200));
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl std::io::Read for HttpRangeSource {
+	fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+		let want_upto = self.pos + out.len() as u64 + URL_PREFETCH_AHEAD_BYTES;
+		self.ensure_filled(want_upto).map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+		let offset = (self.pos - self.buf_start) as usize;
+		if offset >= self.buf.len() { return Ok(0); }
+		let n = (self.buf.len() - offset).min(out.len());
+		out[..n].copy_from_slice(&self.buf[offset..offset + n]);
+		self.pos += n as u64;
+		// Drop everything before the read cursor so a long-running stream doesn't
+		// keep every downloaded byte resident in memory.
+		let drain_to = (self.pos - self.buf_start) as usize;
+		if drain_to > 0 {
+			self.buf.drain(..drain_to);
+			self.buf_start += drain_to as u64;
+		}
+		Ok(n)
+	}
+}
+
+impl std::io::Seek for HttpRangeSource {
+	fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+		let new_pos = match pos {
+			std::io::SeekFrom::Start(p) => p,
+			std::io::SeekFrom::End(p) => {
+				let len = self.total_len.ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "stream length unknown"))?;
+				(len as i64 + p).max(0) as u64
+			}
+			std::io::SeekFrom::Current(p) => (self.pos as i64 + p).max(0) as u64,
+		};
+		// Bytes before the read cursor have already been dropped, so a backward seek restarts
+		// the download at the new position.
+		if new_pos < self.buf_start {
+			self.buf.clear();
+			self.buf_start = new_pos;
+			self.high_water = new_pos;
+		}
+		self.pos = new_pos;
+		Ok(self.pos)
+	}
+}
+
+impl symphonia::core::io::MediaSource for HttpRangeSource {
+	fn is_seekable(&self) -> bool { self.total_len.is_some() }
+	fn byte_len(&self) -> Option<u64> { self.total_len }
+}
+
+// Background decode loop feeding a ring buffer of PCM ahead of the playback cursor. Blocks
+// (briefly, via sleep) only when the ring buffer is full, which in turn only happens once
+// decoding has got ahead of playback — the reader underneath is what blocks on the network.
+struct UrlStream {
+	left: Arc<Mutex<std::collections::VecDeque<f32>>>,
+	right: Arc<Mutex<std::collections::VecDeque<f32>>>,
+	health: Arc<Mutex<UrlBufferHealth>>,
+	events: Option<EventSender>,
+}
+
+impl UrlStream {
+	fn spawn(url: String, fs: u32, events: Option<EventSender>) -> Self {
+		let health = Arc::new(Mutex::new(UrlBufferHealth::default()));
+		let left = Arc::new(Mutex::new(std::collections::VecDeque::new()));
+		let right = Arc::new(Mutex::new(std::collections::VecDeque::new()));
+		let ring_cap = (fs as f64 * URL_RING_CAPACITY_SECONDS) as usize;
+		{
+			let left = left.clone(); let right = right.clone(); let health = health.clone();
+			std::thread::spawn(move || {
+				if let Err(e) = Self::run(url, fs, ring_cap, left, right, health) {
+					eprintln!("url source error: {}", e);
+				}
+			});
+		}
+		Self { left, right, health, events }
+	}
+
+	fn run(
+		url: String, fs: u32, ring_cap: usize,
+		left: Arc<Mutex<std::collections::VecDeque<f32>>>,
+		right: Arc<Mutex<std::collections::VecDeque<f32>>>,
+		health: Arc<Mutex<UrlBufferHealth>>,
+	) -> anyhow::Result<()> {
+		use symphonia::core::codecs::DecoderOptions;
+		use symphonia::core::formats::FormatOptions;
+		use symphonia::core::io::MediaSourceStream;
+		use symphonia::core::meta::MetadataOptions;
+		use symphonia::core::probe::Hint;
+
+		let source = HttpRangeSource::new(url.clone(), health.clone())?;
+		let mss = MediaSourceStream::new(Box::new(source), Default::default());
+		let mut hint = Hint::new();
+		if let Some(ext) = url.rsplit('.').next() { hint.with_extension(ext); }
+		let probed = symphonia::default::get_probe().format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())?;
+		let mut format = probed.format;
+		let track = format.default_track().ok_or_else(|| anyhow::anyhow!("no default track"))?;
+		let src_fs = track.codec_params.sample_rate.unwrap_or(fs);
+		let dec_opt = DecoderOptions { verify: true, ..Default::default() };
+		let mut decoder = symphonia::default::get_codecs().make(&track.codec_params, &dec_opt)?;
+
+		loop {
+			let packet = match format.next_packet() { Ok(p) => p, Err(_) => break };
+			let audio_buf = match decoder.decode(&packet) { Ok(b) => b, Err(_) => continue };
+			let spec = *audio_buf.spec();
+			let chans = spec.channels.count();
+			let (mut l, mut r) = (Vec::new(), Vec::new());
+			if let AudioBufferRef::F32(buf) = audio_buf {
+				let frames = buf.frames();
+				let mut sbuf = SampleBuffer::<f32>::new(frames as u64, *buf.spec());
+				sbuf.copy_interleaved_ref(AudioBufferRef::F32(buf));
+				let data = sbuf.samples();
+				for f in 0..frames {
+					let lv = data[f * chans];
+					let rv = if chans > 1 { data[f * chans + 1] } else { lv };
+					l.push(lv); r.push(rv);
+				}
+			}
+			let (l, r) = linear_resample_stereo(&l, &r, src_fs, fs);
+
+			// Back off while the ring buffer is full; the decoder underneath keeps pulling
+			// range requests ahead of the cursor so we resume as soon as there's room.
+			loop {
+				if left.lock().unwrap().len() < ring_cap { break; }
+				std::thread::sleep(Duration::from_millis(20));
+			}
+			left.lock().unwrap().extend(l);
+			right.lock().unwrap().extend(r);
+			health.lock().unwrap().buffered_samples = left.lock().unwrap().len();
+		}
+		Ok(())
+	}
+
+	// Drains up to `n` samples per channel; pads with silence and counts an underrun when
+	// the decoder hasn't kept the ring buffer filled (e.g. the network fell behind).
+	fn next(&mut self, n: usize) -> (Vec<f32>, Vec<f32>) {
+		let mut l = self.left.lock().unwrap();
+		let mut r = self.right.lock().unwrap();
+		let avail = n.min(l.len()).min(r.len());
+		let mut out_l: Vec<f32> = l.drain(0..avail).collect();
+		let mut out_r: Vec<f32> = r.drain(0..avail).collect();
+		if avail < n {
+			health_record_underrun(&self.health);
+			emit_event(&self.events, "url source buffer underrun");
+			out_l.resize(n, 0.0);
+			out_r.resize(n, 0.0);
+		}
+		let mut h = self.health.lock().unwrap();
+		h.buffered_samples = l.len();
+		(out_l, out_r)
+	}
+}
+
+fn health_record_underrun(health: &Arc<Mutex<UrlBufferHealth>>) { health.lock().unwrap().underruns += 1; }
+
+// Decodes one playlist track at a time, advancing (or looping, per `PlaylistMode`) on EOF and
+// reacting to a live `/skip` or reorder through the shared `PlaylistState`. A track that fails
+// to decode is skipped rather than stalling the whole playlist.
+struct PlaylistFeed {
+	fs: u32,
+	shared: SharedPlaylist,
+	left: Vec<f32>,
+	right: Vec<f32>,
+	pos: usize,
+	loaded_index: Option<usize>,
+	pending_meta: Option<(String, String)>,
+	events: Option<EventSender>,
+	ended: bool,
+}
+
+impl PlaylistFeed {
+	fn spawn(shared: SharedPlaylist, fs: u32, events: Option<EventSender>) -> Self {
+		let mut feed = Self { fs, shared, left: Vec::new(), right: Vec::new(), pos: 0, loaded_index: None, pending_meta: None, events, ended: false };
+		feed.load_current();
+		feed
+	}
+
+	fn load_current(&mut self) {
+		let current = {
+			let st = self.shared.lock().unwrap();
+			st.items.get(st.index).cloned().map(|item| (item, st.index))
+		};
+		let (item, index) = match current {
+			Some(v) => v,
+			None => {
+				self.left = vec![0.0; self.fs as usize];
+				self.right = self.left.clone();
+				self.pos = 0;
+				self.loaded_index = None;
+				return;
+			}
+		};
+		match decode_audio_file(&item.path) {
+			Ok((l, r, src_fs)) => {
+				let (l2, r2) = linear_resample_stereo(&l, &r, src_fs, self.fs);
+				self.left = l2;
+				self.right = r2;
+				emit_event(&self.events, format!("playlist: now playing {}", item.path));
+			}
+			Err(e) => {
+				eprintln!("playlist item {} failed to decode: {}", item.path, e);
+				emit_event(&self.events, format!("playlist: skipping {} ({})", item.path, e));
+				self.left = Vec::new();
+				self.right = Vec::new();
+			}
+		}
+		self.pos = 0;
+		self.loaded_index = Some(index);
+		if item.ps.is_some() || item.rt.is_some() {
+			self.pending_meta = Some((item.ps.unwrap_or_default(), item.rt.unwrap_or_default()));
+		}
+	}
+
+	// Advances the shared index past the current track; returns false if the playlist ended
+	// and `PlaylistMode::Stop` means playback should hold silence rather than wrap, setting
+	// `ended` so `next()` stops re-triggering a reload of the final track.
+	fn advance(&mut self) -> bool {
+		let mut st = self.shared.lock().unwrap();
+		if st.items.is_empty() { return false; }
+		st.index += 1;
+		if st.index >= st.items.len() {
+			match st.mode {
+				PlaylistMode::Loop => { st.index = 0; true }
+				PlaylistMode::Stop => {
+					st.index = st.items.len() - 1;
+					self.ended = true;
+					false
+				}
+			}
+		} else {
+			true
+		}
+	}
+
+	fn take_pending_meta(&mut self) -> Option<(String, String)> { self.pending_meta.take() }
+
+	fn next(&mut self, n: usize) -> (Vec<f32>, Vec<f32>) {
+		if self.ended {
+			// `/queue/add` can append a new track to a playlist that already ran out under
+			// `PlaylistMode::Stop`; re-check rather than staying silent forever so the live
+			// reordering/append flow can resume playback without a restart.
+			let has_more = {
+				let st = self.shared.lock().unwrap();
+				match self.loaded_index {
+					Some(idx) => st.items.len() > idx + 1,
+					None => !st.items.is_empty(),
+				}
+			};
+			if has_more { self.ended = false; } else { return (vec![0.0; n], vec![0.0; n]); }
+		}
+		let skip_requested = {
+			let mut st = self.shared.lock().unwrap();
+			std::mem::replace(&mut st.skip_requested, false)
+		};
+		let index_changed = {
+			let st = self.shared.lock().unwrap();
+			Some(st.index) != self.loaded_index
+		};
+		if skip_requested {
+			if self.advance() { self.load_current(); }
+		} else if index_changed {
+			self.load_current();
+		}
+		if self.ended { return (vec![0.0; n], vec![0.0; n]); }
+		if self.left.is_empty() {
+			// The loaded track failed to decode: move past it so the next call plays the one after.
+			if self.advance() { self.load_current(); }
+			return (vec![0.0; n], vec![0.0; n]);
+		}
+		let mut out_l = Vec::with_capacity(n);
+		let mut out_r = Vec::with_capacity(n);
+		for _ in 0..n {
+			if self.ended {
+				out_l.push(0.0);
+				out_r.push(0.0);
+				continue;
+			}
+			if self.pos >= self.left.len() {
+				let advanced = self.advance();
+				if !advanced {
+					out_l.push(0.0);
+					out_r.push(0.0);
+					continue;
+				}
+				self.load_current();
+				if self.left.is_empty() {
+					out_l.push(0.0);
+					out_r.push(0.0);
+					continue;
+				}
+			}
+			out_l.push(self.left[self.pos]);
+			out_r.push(self.right[self.pos]);
+			self.pos += 1;
+		}
+		(out_l, out_r)
+	}
+}
+
+// Unifies a preloaded (tone/file) buffer, a live streaming URL source, and a playlist behind
+// one "give me the next N stereo samples" interface, so the MPX loops don't need to care which
+// kind of source is feeding them.
+enum AudioFeed {
+	Memory { left: Vec<f32>, right: Vec<f32>, pos: usize },
+	Url(UrlStream),
+	Playlist(PlaylistFeed),
+}
+
+impl AudioFeed {
+	fn next(&mut self, n: usize) -> (Vec<f32>, Vec<f32>) {
+		match self {
+			AudioFeed::Memory { left, right, pos } => {
+				if left.is_empty() { return (vec![0.0; n], vec![0.0; n]); }
+				let mut out_l = Vec::with_capacity(n);
+				let mut out_r = Vec::with_capacity(n);
+				for _ in 0..n {
+					if *pos >= left.len() { *pos = 0; }
+					out_l.push(left[*pos]);
+					out_r.push(right[*pos]);
+					*pos += 1;
+				}
+				(out_l, out_r)
+			}
+			AudioFeed::Url(stream) => stream.next(n),
+			AudioFeed::Playlist(feed) => feed.next(n),
+		}
+	}
+
+	// Only playlists carry per-track RDS overrides; other sources never have a pending update.
+	fn take_pending_meta(&mut self) -> Option<(String, String)> {
+		match self {
+			AudioFeed::Playlist(feed) => feed.take_pending_meta(),
+			_ => None,
+		}
+	}
 }
 
 fn decode_audio_file(path: &str) -> anyhow::Result<(Vec<f32>, Vec<f32>, u32)> {
@@ -520,84 +1357,123 @@ fn process_logo_to_bits(path: &str) -> anyhow::Result<Vec<u8>> {
 
 #[cfg(feature = "audio")]
 async fn start_stream(cfg: StreamConfig, state: Arc<Mutex<RuntimeState>>) -> anyhow::Result<()> {
-	// Prepare audio source buffer aligned to fs
-	let (mut left, mut right) = match cfg.source.clone() {
+	let events = state.lock().unwrap().events.clone();
+	emit_event(&events, format!("stream started at {} Hz", cfg.fs));
+	// Prepare the audio feed: a preloaded buffer for Tone/File, a live ring-buffered decode
+	// for Url, or a track-by-track feed for Playlist. All are driven through the same
+	// `AudioFeed::next` interface below.
+	let mut feed = match cfg.source.clone() {
 		SourceKind::Tone { freq } => {
 			let (l, r) = generate_tone_stereo(cfg.fs, 60.0, freq, -6.0);
-			(l, r)
+			AudioFeed::Memory { left: l, right: r, pos: 0 }
 		},
 		SourceKind::File { path } => {
 			let (l, r, src_fs) = decode_audio_file(&path)?;
 			let (l2, r2) = linear_resample_stereo(&l, &r, src_fs, cfg.fs);
-			(l2, r2)
+			AudioFeed::Memory { left: l2, right: r2, pos: 0 }
+		}
+		SourceKind::Url { url } => {
+			let stream = UrlStream::spawn(url, cfg.fs, events.clone());
+			state.lock().unwrap().url_health = Some(stream.health.clone());
+			AudioFeed::Url(stream)
+		}
+		SourceKind::Playlist { items, mode } => {
+			let shared: SharedPlaylist = Arc::new(Mutex::new(PlaylistState::new(items, mode)));
+			state.lock().unwrap().playlist = Some(shared.clone());
+			AudioFeed::Playlist(PlaylistFeed::spawn(shared, cfg.fs, events.clone()))
 		}
 	};
-	if left.is_empty() { left = vec![0.0; (cfg.fs as f64) as usize]; right = left.clone(); }
+	if let AudioFeed::Memory { left, .. } = &feed {
+		if left.is_empty() {
+			feed = AudioFeed::Memory { left: vec![0.0; cfg.fs as usize], right: vec![0.0; cfg.fs as usize], pos: 0 };
+		}
+	}
 	let mut rds_gen = RdsGen::new(RdsConfig { pi: cfg.pi, ps: cfg.ps.clone(), rt: cfg.rt.clone() });
 	rds_gen.set_logo_bits(cfg.logo_bits.clone());
 	let mut rds_bits = rds_gen.generate((RDS_BITRATE * 2.0) as usize);
 
-	let host = cpal::default_host();
-	let device = if let Some(idx) = cfg.device_index {
-		let mut it = host.output_devices()?;
-		it.nth(idx).ok_or_else(|| anyhow::anyhow!("device index not found"))?
-	} else { host.default_output_device().ok_or_else(|| anyhow::anyhow!("no default output device"))? };
-	let mut supported = device.supported_output_configs()?;
-	let mut chosen = None;
-	while let Some(cfg) = supported.next() {
-		let sr = cfg.min_sample_rate().0..=cfg.max_sample_rate().0;
-		if sr.contains(&cfg.fs) || sr.contains(&cfg.fs) {} // dummy to satisfy borrow
-	}
-	let mut best_diff = u32::MAX;
-	let mut best_cfg = None;
-	for cfg in device.supported_output_configs()? {
-		let range = cfg.min_sample_rate().0..=cfg.max_sample_rate().0;
-		let target = if range.contains(&cfg.fs) { cfg.fs } else { cfg.min_sample_rate().0 };
-		let diff = (target as i64 - cfg.fs as i64).unsigned_abs();
-		if diff < best_diff { best_diff = diff; best_cfg = Some(cfg.with_sample_rate(cpal::SampleRate(target))); }
-	}
-	let out_cfg = best_cfg.ok_or_else(|| anyhow::anyhow!("no supported output config"))?;
-	let sr = out_cfg.sample_rate().0;
-	let channels = out_cfg.channels() as usize;
-
-	let rb = HeapRb::<f32>::new((sr as usize) * 2);
-	let (mut prod, mut cons) = rb.split();
 	let stop = state.lock().unwrap().stop_flag.clone();
-
 	let fs_target = cfg.fs;
 	let enable_rds2 = cfg.enable_rds2;
 	let pilot = cfg.pilot; let rds = cfg.rds; let rds2 = cfg.rds2;
-	let mut pos = 0usize;
-	let bg = tokio::spawn(async move {
-		let mut local_left = left;
-		let mut local_right = right;
-		loop {
-			if stop.load(Ordering::SeqCst) { break; }
-			let need = prod.free_len().min(1024);
-			if need == 0 { tokio::time::sleep(Duration::from_millis(5)).await; continue; }
-			let samples = need;
-			let end = pos + samples;
-			if end > local_left.len() { pos = 0; }
-			let l = &local_left[pos..pos + samples.min(local_left.len()-pos)];
-			let r = &local_right[pos..pos + samples.min(local_right.len()-pos)];
-			let bits_needed = ((samples as f64 / fs_target as f64) * RDS_BITRATE) as usize + 208;
-			if rds_bits.len() < bits_needed { rds_bits.extend(rds_gen.generate((RDS_BITRATE * 2.0) as usize)); }
-			let bits_block: Vec<u8> = rds_bits.drain(0..bits_needed.min(rds_bits.len())).collect();
-			let mpx = make_mpx(l, r, fs_target, pilot, rds, rds2, &bits_block, enable_rds2);
-			for &s in &mpx { let _ = prod.push(s); }
-			pos += samples;
+	let mut processor = AudioProcessor::new(fs_target, cfg.loudness_target);
+
+	match cfg.sink.clone() {
+		SinkKind::Device { index } => {
+			let host = cpal::default_host();
+			let device = if let Some(idx) = index {
+				let mut it = host.output_devices()?;
+				it.nth(idx).ok_or_else(|| anyhow::anyhow!("device index not found"))?
+			} else { host.default_output_device().ok_or_else(|| anyhow::anyhow!("no default output device"))? };
+			let mut best_diff = u32::MAX;
+			let mut best_cfg = None;
+			for out_cfg in device.supported_output_configs()? {
+				let range = out_cfg.min_sample_rate().0..=out_cfg.max_sample_rate().0;
+				let target = if range.contains(&fs_target) { fs_target } else { out_cfg.min_sample_rate().0 };
+				let diff = (target as i64 - fs_target as i64).unsigned_abs();
+				if diff < best_diff { best_diff = diff; best_cfg = Some(out_cfg.with_sample_rate(cpal::SampleRate(target))); }
+			}
+			let out_cfg = best_cfg.ok_or_else(|| anyhow::anyhow!("no supported output config"))?;
+			let sr = out_cfg.sample_rate().0;
+			let channels = out_cfg.channels() as usize;
+
+			let rb = HeapRb::<f32>::new((sr as usize) * 2);
+			let (mut prod, mut cons) = rb.split();
+			let bg = tokio::spawn(async move {
+				loop {
+					if stop.load(Ordering::SeqCst) { break; }
+					let need = prod.free_len().min(1024);
+					if need == 0 { tokio::time::sleep(Duration::from_millis(5)).await; continue; }
+					let (l, r) = feed.next(need);
+					if let Some((ps, rt)) = feed.take_pending_meta() { rds_gen.set_ps_rt(ps, rt); }
+					let bits_needed = ((need as f64 / fs_target as f64) * RDS_BITRATE) as usize + 208;
+					if rds_bits.len() < bits_needed { rds_bits.extend(rds_gen.generate((RDS_BITRATE * 2.0) as usize)); }
+					let bits_block: Vec<u8> = rds_bits.drain(0..bits_needed.min(rds_bits.len())).collect();
+					let mpx = make_mpx(&l, &r, fs_target, pilot, rds, rds2, &bits_block, enable_rds2, &mut processor);
+					for &s in &mpx { let _ = prod.push(s); }
+				}
+			});
+
+			let err_events = events.clone();
+			let err_fn = move |e| { eprintln!("stream error: {}", e); emit_event(&err_events, format!("device error: {}", e)); };
+			let stream = match out_cfg.sample_format() {
+				cpal::SampleFormat::F32 => device.build_output_stream(&out_cfg.config(), move |data: &mut [f32], _| write_from_rb(data, channels, &mut cons), err_fn, None)?,
+				cpal::SampleFormat::I16 => device.build_output_stream(&out_cfg.config(), move |data: &mut [i16], _| write_from_rb_i16(data, channels, &mut cons), err_fn, None)?,
+				cpal::SampleFormat::U16 => device.build_output_stream(&out_cfg.config(), move |data: &mut [u16], _| write_from_rb_u16(data, channels, &mut cons), err_fn, None)?,
+				_ => anyhow::bail!("unsupported sample format"),
+			};
+			stream.play()?;
+			state.lock().unwrap().bg_task = Some(bg);
 		}
-	});
-
-	let err_fn = |e| eprintln!("stream error: {}", e);
-	let stream = match out_cfg.sample_format() {
-		cpal::SampleFormat::F32 => device.build_output_stream(&out_cfg.config(), move |data: &mut [f32], _| write_from_rb(data, channels, &mut cons), err_fn, None)?,
-		cpal::SampleFormat::I16 => device.build_output_stream(&out_cfg.config(), move |data: &mut [i16], _| write_from_rb_i16(data, channels, &mut cons), err_fn, None)?,
-		cpal::SampleFormat::U16 => device.build_output_stream(&out_cfg.config(), move |data: &mut [u16], _| write_from_rb_u16(data, channels, &mut cons), err_fn, None)?,
-		_ => anyhow::bail!("unsupported sample format"),
-	};
-	stream.play()?;
-	state.lock().unwrap().bg_task = Some(bg);
+		SinkKind::Network { addr, transport, quic_cert_sha256 } => {
+			let queue: SharedBasebandQueue = Arc::new(Mutex::new(BasebandQueue::default()));
+			let sender_stop = stop.clone();
+			let sender_queue = queue.clone();
+			let sink_events = events.clone();
+			tokio::spawn(async move {
+				if let Err(e) = run_network_sink(addr, transport, quic_cert_sha256, sender_queue, sender_stop).await {
+					eprintln!("network sink error: {}", e);
+					emit_event(&sink_events, format!("network sink error: {}", e));
+				}
+			});
+			let mut seq: u64 = 0;
+			let bg = tokio::spawn(async move {
+				loop {
+					if stop.load(Ordering::SeqCst) { break; }
+					let (l, r) = feed.next(BASEBAND_FRAME_SAMPLES);
+					if let Some((ps, rt)) = feed.take_pending_meta() { rds_gen.set_ps_rt(ps, rt); }
+					let bits_needed = ((BASEBAND_FRAME_SAMPLES as f64 / fs_target as f64) * RDS_BITRATE) as usize + 208;
+					if rds_bits.len() < bits_needed { rds_bits.extend(rds_gen.generate((RDS_BITRATE * 2.0) as usize)); }
+					let bits_block: Vec<u8> = rds_bits.drain(0..bits_needed.min(rds_bits.len())).collect();
+					let mpx = make_mpx(&l, &r, fs_target, pilot, rds, rds2, &bits_block, enable_rds2, &mut processor);
+					queue_frame_drop_oldest(&queue, BasebandFrame { seq, sample_rate: fs_target, samples: mpx });
+					seq += 1;
+					tokio::time::sleep(Duration::from_millis((BASEBAND_FRAME_SAMPLES as u64 * 1000) / fs_target.max(1) as u64)).await;
+				}
+			});
+			state.lock().unwrap().bg_task = Some(bg);
+		}
+	}
 	Ok(())
 }
 
@@ -633,6 +1509,7 @@ fn write_from_rb_u16(out: &mut [u16], channels: usize, cons: &mut Consumer<f32>)
 
 async fn stop_stream(state: Arc<Mutex<RuntimeState>>) {
 	let mut s = state.lock().unwrap();
+	if s.bg_task.is_some() { emit_event(&s.events, "stream stopped"); }
 	s.stop_flag.store(true, Ordering::SeqCst);
 	if let Some(h) = s.bg_task.take() { let _ = h.abort(); }
 }
@@ -678,10 +1555,36 @@ static TEMPLATE: &str = r#"<!doctype html>
 			<label>Output Device</label>
 			<select id=\"deviceSelect\" class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" name=\"device\"></select>
 		  </div>
+		  <div class=\"md:col-span-2\">
+			<div class=\"flex items-center gap-6\">
+			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"sink\" value=\"device\" checked /> Local device</label>
+			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"sink\" value=\"network\" /> Network</label>
+			</div>
+		  </div>
+		  <div id=\"sinkNetworkFields\" class=\"hidden md:col-span-2 grid grid-cols-1 md:grid-cols-2 gap-4\">
+			<div>
+			  <label>Remote Address (host:port)</label>
+			  <input class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" type=\"text\" name=\"sink_addr\" placeholder=\"192.168.1.50:9000\" />
+			</div>
+			<div>
+			  <label>Transport</label>
+			  <select class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" name=\"sink_transport\">
+				<option value=\"udp\">UDP</option>
+				<option value=\"tcp\">TCP</option>
+				<option value=\"quic\">QUIC</option>
+			  </select>
+			</div>
+			<div class=\"md:col-span-2\">
+			  <label>QUIC Certificate SHA-256 (hex, optional -- pins the expected cert; leave blank to skip TLS verification)</label>
+			  <input class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" type=\"text\" name=\"sink_quic_cert_sha256\" placeholder=\"e.g. 9f86d0...\" />
+			</div>
+		  </div>
 		  <div class=\"md:col-span-2\">
 			<div class=\"flex items-center gap-6\">
 			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"source\" value=\"tone\" checked /> Tone</label>
 			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"source\" value=\"file\" /> File</label>
+			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"source\" value=\"url\" /> URL</label>
+			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"source\" value=\"playlist\" /> Playlist</label>
 			</div>
 		  </div>
 		  <div id=\"toneFields\">
@@ -695,6 +1598,23 @@ static TEMPLATE: &str = r#"<!doctype html>
 			<div id=\"dropzone\" class=\"dropzone mt-1 rounded px-4 py-6 text-sm text-gray-300 flex items-center justify-center\">Drop audio here or click to browse</div>
 			<input id=\"audioInput\" class=\"hidden\" type=\"file\" name=\"audio\" accept=\"audio/*\" />
 		  </div>
+		  <div id=\"urlFields\" class=\"hidden\">
+			<label>Stream URL</label>
+			<input class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" type=\"text\" name=\"source_url\" placeholder=\"https://relay.example.com/stream.mp3\" />
+		  </div>
+		  <div id=\"playlistFields\" class=\"hidden md:col-span-2\">
+			<label>Playlist (ends/loops per mode below)</label>
+			<div class=\"flex items-center gap-6 mt-1\">
+			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"playlist_mode\" value=\"loop\" checked /> Loop</label>
+			  <label class=\"inline-flex items-center gap-2\"><input type=\"radio\" name=\"playlist_mode\" value=\"stop\" /> Stop at end</label>
+			</div>
+			<ul id=\"queueList\" class=\"mt-2 space-y-1 text-sm\"></ul>
+			<div class=\"flex gap-2 mt-2\">
+			  <input id=\"queueAddPath\" class=\"flex-1 bg-transparent border rounded px-3 py-2 border-white/10\" type=\"text\" placeholder=\"Path to audio file\" />
+			  <button id=\"queueAddBtn\" type=\"button\" class=\"px-3 py-2 rounded border border-white/10\">Add</button>
+			  <button id=\"skipBtn\" type=\"button\" class=\"px-3 py-2 rounded border border-white/10\">Skip</button>
+			</div>
+		  </div>
 		  <div class=\"md:col-span-2 flex items-center gap-3 mt-2\">
 			<button id=\"startBtn\" class=\"btn-primary px-4 py-2 rounded text-black font-medium\" type=\"submit\">Start</button>
 			<button id=\"stopBtn\" class=\"px-4 py-2 rounded border border-white/10\" type=\"button\">Stop</button>
@@ -729,6 +1649,10 @@ static TEMPLATE: &str = r#"<!doctype html>
 			<label>RDS2</label>
 			<input class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" name=\"rds2\" value=\"0.01\" />
 		  </div>
+		  <div>
+			<label>Loudness Target (LUFS)</label>
+			<input class=\"mt-1 w-full bg-transparent border rounded px-3 py-2 border-white/10\" type=\"number\" step=\"0.1\" name=\"loudness_target\" value=\"-23\" />
+		  </div>
 		  <div class=\"col-span-2\">
 			<label class=\"inline-flex items-center gap-2\"><input type=\"checkbox\" name=\"enable_rds2\" checked /> Enable RDS2</label>
 		  </div>
@@ -750,22 +1674,70 @@ static TEMPLATE: &str = r#"<!doctype html>
   <script>
     async function fetchJSON(url){ const r = await fetch(url); if(!r.ok) throw new Error('HTTP '+r.status); return r.json(); }
     async function refreshDevices(){ try { const list = await fetchJSON('/devices'); const sel = document.getElementById('deviceSelect'); sel.innerHTML = '<option value="">Default</option>'; list.forEach(d=>{ const o=document.createElement('option'); o.value=d.index; o.textContent=`${d.index} · ${d.name} (${d.channels}ch)`; sel.appendChild(o); }); } catch(e){ console.warn(e); } }
-    async function refreshStatus(){ try { const st = await fetchJSON('/status'); const chip = document.getElementById('statusChip'); chip.textContent = st.streaming ? `Streaming · ${st.fs} Hz` : 'Idle'; chip.className = 'chip px-3 py-1 rounded text-sm ' + (st.streaming ? 'text-emerald-300' : 'text-gray-300'); } catch(e){} }
-    async function refreshConfig(){ try { const c = await fetchJSON('/config'); if(!c) return; document.querySelector('input[name=\"fs\"]').value=c.fs||192000; document.querySelector('input[name=\"pi\"]').value=c.pi_hex||'0x1234'; document.querySelector('input[name=\"ps\"]').value=c.ps||''; document.querySelector('input[name=\"rt\"]').value=c.rt||''; document.querySelector('input[name=\"pilot\"]').value=c.pilot||0.08; document.querySelector('input[name=\"rds\"]').value=c.rds||0.03; document.querySelector('input[name=\"rds2\"]').value=c.rds2||0.01; if(c.enable_rds2){ document.querySelector('input[name=\"enable_rds2\"]').checked=true; } } catch(e){} }
-
-    function hookSourceToggle(){ const radios = document.querySelectorAll('input[name=source]'); const tone = document.getElementById('toneFields'); const file = document.getElementById('fileFields'); const update = ()=>{ const v = document.querySelector('input[name=source]:checked').value; tone.classList.toggle('hidden', v!=='tone'); file.classList.toggle('hidden', v!=='file'); }; radios.forEach(r=>r.addEventListener('change', update)); update(); }
+    async function refreshStatus(){ try { const st = await fetchJSON('/status'); const chip = document.getElementById('statusChip'); let label = st.streaming ? `Streaming · ${st.fs} Hz` : 'Idle'; if(st.streaming && st.url_buffer){ label += ` · buf ${st.url_buffer.buffered_samples}` + (st.url_buffer.underruns ? ` · ${st.url_buffer.underruns} underruns` : ''); } chip.textContent = label; chip.className = 'chip px-3 py-1 rounded text-sm ' + (st.streaming ? 'text-emerald-300' : 'text-gray-300'); } catch(e){} }
+    async function refreshConfig(){ try { const resp = await fetchJSON('/config'); const c = resp.status === 'success' ? resp.content : null; if(!c) return; document.querySelector('input[name=\"fs\"]').value=c.fs||192000; document.querySelector('input[name=\"pi\"]').value=c.pi_hex||'0x1234'; document.querySelector('input[name=\"ps\"]').value=c.ps||''; document.querySelector('input[name=\"rt\"]').value=c.rt||''; document.querySelector('input[name=\"pilot\"]').value=c.pilot||0.08; document.querySelector('input[name=\"rds\"]').value=c.rds||0.03; document.querySelector('input[name=\"rds2\"]').value=c.rds2||0.01; document.querySelector('input[name=\"loudness_target\"]').value=c.loudness_target||-23; if(c.enable_rds2){ document.querySelector('input[name=\"enable_rds2\"]').checked=true; } } catch(e){} }
+
+    function hookSourceToggle(){ const radios = document.querySelectorAll('input[name=source]'); const tone = document.getElementById('toneFields'); const file = document.getElementById('fileFields'); const url = document.getElementById('urlFields'); const playlist = document.getElementById('playlistFields'); const update = ()=>{ const v = document.querySelector('input[name=source]:checked').value; tone.classList.toggle('hidden', v!=='tone'); file.classList.toggle('hidden', v!=='file'); url.classList.toggle('hidden', v!=='url'); playlist.classList.toggle('hidden', v!=='playlist'); if(v==='playlist'){ refreshQueue(); } }; radios.forEach(r=>r.addEventListener('change', update)); update(); }
+
+    let queueDragFrom = null;
+    async function refreshQueue(){
+      try {
+        const q = await fetchJSON('/queue');
+        const list = document.getElementById('queueList');
+        list.innerHTML = '';
+        q.items.forEach((item, i) => {
+          const li = document.createElement('li');
+          li.draggable = true;
+          li.dataset.index = i;
+          li.className = 'flex items-center justify-between gap-2 px-2 py-1 rounded border border-white/10 cursor-move ' + (q.current_index === i ? 'text-emerald-300' : 'text-gray-300');
+          const label = document.createElement('span');
+          label.textContent = item.path + (item.ps ? ` · PS:${item.ps}` : '') + (item.rt ? ` · RT:${item.rt}` : '');
+          const rm = document.createElement('button');
+          rm.type = 'button'; rm.textContent = '✕'; rm.className = 'px-2';
+          rm.addEventListener('click', async () => { await fetch('/queue/remove', { method: 'POST', headers: {'Content-Type':'application/json'}, body: JSON.stringify({ index: i }) }); refreshQueue(); });
+          li.appendChild(label); li.appendChild(rm);
+          li.addEventListener('dragstart', () => { queueDragFrom = i; });
+          li.addEventListener('dragover', (e) => e.preventDefault());
+          li.addEventListener('drop', async (e) => {
+            e.preventDefault();
+            if (queueDragFrom === null || queueDragFrom === i) return;
+            const order = q.items.map((_, idx) => idx);
+            const [moved] = order.splice(queueDragFrom, 1);
+            order.splice(i, 0, moved);
+            queueDragFrom = null;
+            await fetch('/queue/reorder', { method: 'POST', headers: {'Content-Type':'application/json'}, body: JSON.stringify({ order }) });
+            refreshQueue();
+          });
+          list.appendChild(li);
+        });
+      } catch(e){ console.warn(e); }
+    }
+
+    function hookQueueControls(){
+      document.getElementById('queueAddBtn').addEventListener('click', async () => {
+        const input = document.getElementById('queueAddPath');
+        if (!input.value) return;
+        await fetch('/queue/add', { method: 'POST', headers: {'Content-Type':'application/json'}, body: JSON.stringify({ path: input.value }) });
+        input.value = '';
+        refreshQueue();
+      });
+      document.getElementById('skipBtn').addEventListener('click', async () => { await fetch('/skip', { method: 'POST' }); refreshQueue(); });
+    }
+    function hookSinkToggle(){ const radios = document.querySelectorAll('input[name=sink]'); const net = document.getElementById('sinkNetworkFields'); const update = ()=>{ const v = document.querySelector('input[name=sink]:checked').value; net.classList.toggle('hidden', v!=='network'); }; radios.forEach(r=>r.addEventListener('change', update)); update(); }
 
     function setupDropzone(){ const dz=document.getElementById('dropzone'); const inp=document.getElementById('audioInput'); dz.addEventListener('click',()=>inp.click()); dz.addEventListener('dragover',(e)=>{ e.preventDefault(); dz.classList.add('border-cyan-400');}); dz.addEventListener('dragleave',()=>dz.classList.remove('border-cyan-400')); dz.addEventListener('drop',(e)=>{ e.preventDefault(); dz.classList.remove('border-cyan-400'); if(e.dataTransfer.files.length){ inp.files=e.dataTransfer.files; dz.textContent=e.dataTransfer.files[0].name; } }); inp.addEventListener('change',()=>{ if(inp.files.length){ dz.textContent=inp.files[0].name; } }); }
 
     function setupLogoPreview(){ const inp=document.getElementById('logoInput'); const canvas=document.getElementById('logoPreview'); const ctx=canvas.getContext('2d'); canvas.width=512; canvas.height=96; inp.addEventListener('change',()=>{ const f=inp.files[0]; if(!f) return; const img=new Image(); img.onload=()=>{ const w=img.width,h=img.height; const scale=Math.min(canvas.width*0.9/w, canvas.height*0.9/h); const nw=w*scale, nh=h*scale; ctx.clearRect(0,0,canvas.width,canvas.height); ctx.globalAlpha=0.3; ctx.fillStyle='#0a0a0a'; ctx.fillRect(0,0,canvas.width,canvas.height); ctx.globalAlpha=1.0; ctx.drawImage(img,(canvas.width-nw)/2,(canvas.height-nh)/2,nw,nh); }; img.src=URL.createObjectURL(f); }); }
 
-    async function startStreaming(){ const cf=document.getElementById('controlForm'); const rf=document.getElementById('rdsForm'); const fd=new FormData(cf); new FormData(rf).forEach((v,k)=>fd.append(k,v)); const r=await fetch('/start',{ method:'POST', body:fd }); if(r.redirected){ window.location=r.url; } }
-    async function stopStreaming(){ await fetch('/stop'); }
+    function logLine(text){ const log = document.getElementById('log'); log.textContent += text + '\n'; log.scrollTop = log.scrollHeight; }
+    async function startStreaming(){ const cf=document.getElementById('controlForm'); const rf=document.getElementById('rdsForm'); const fd=new FormData(cf); new FormData(rf).forEach((v,k)=>fd.append(k,v)); const resp = await (await fetch('/start',{ method:'POST', body:fd })).json(); if(resp.status !== 'success'){ logLine(`start ${resp.status}: ${resp.message}`); } }
+    async function stopStreaming(){ const resp = await (await fetch('/stop')).json(); if(resp.status !== 'success'){ logLine(`stop ${resp.status}: ${resp.message}`); } }
+    function hookEventStream(){ const es = new EventSource('/events'); es.onmessage = (e) => logLine(e.data); }
 
     document.getElementById('controlForm').addEventListener('submit', async (e)=>{ e.preventDefault(); try{ await startStreaming(); }catch(err){ console.error(err);} finally{ setTimeout(refreshStatus,500); }});
     document.getElementById('stopBtn').addEventListener('click', async ()=>{ await stopStreaming(); setTimeout(refreshStatus,200); });
 
-    hookSourceToggle(); setupDropzone(); setupLogoPreview();
+    hookSourceToggle(); hookSinkToggle(); hookQueueControls(); hookEventStream(); setupDropzone(); setupLogoPreview();
     refreshDevices(); refreshConfig(); refreshStatus();
     setInterval(refreshStatus, 2000);
   </script>
@@ -774,7 +1746,40 @@ static TEMPLATE: &str = r#"<!doctype html>
 
 #[cfg(feature = "web")]
 #[derive(Clone)]
-struct AppContext { pool: SqlitePool, state: Arc<Mutex<RuntimeState>>, upload_dir: PathBuf }
+struct AppContext { pool: SqlitePool, state: Arc<Mutex<RuntimeState>>, upload_dir: PathBuf, events: EventSender }
+
+// Uniform shape for `/start`, `/stop`, and `/config` so the front-end can branch on `status`
+// instead of inferring success from a redirect or a bare HTTP code. The `/queue*` and `/skip`
+// handlers return the bare queue status; only a rejected `/queue/add` uses `Failure`.
+#[cfg(feature = "web")]
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum ApiResponse {
+	Success { content: serde_json::Value },
+	Failure { message: String },
+	Fatal { message: String },
+}
+
+#[cfg(feature = "web")]
+impl ApiResponse {
+	fn success(content: impl Serialize) -> Self {
+		Self::Success { content: serde_json::to_value(content).unwrap_or(serde_json::Value::Null) }
+	}
+	fn failure(message: impl Into<String>) -> Self { Self::Failure { message: message.into() } }
+	fn fatal(message: impl Into<String>) -> Self { Self::Fatal { message: message.into() } }
+}
+
+#[cfg(feature = "web")]
+impl axum::response::IntoResponse for ApiResponse {
+	fn into_response(self) -> axum::response::Response { Json(self).into_response() }
+}
+
+#[cfg(feature = "web")]
+async fn events_handler(State(ctx): State<AppContext>) -> Sse<impl futures::Stream<Item = Result<Event, std::convert::Infallible>>> {
+	let stream = BroadcastStream::new(ctx.events.subscribe())
+		.filter_map(|msg| async move { msg.ok().map(|text| Ok(Event::default().data(text))) });
+	Sse::new(stream)
+}
 
 #[cfg(feature = "web")]
 async fn index_handler() -> Html<&'static str> { Html(TEMPLATE) }
@@ -784,7 +1789,11 @@ async fn devices_handler() -> Json<Vec<OutputDeviceInfo>> { Json(list_output_dev
 
 #[cfg(feature = "web")]
 #[derive(Serialize)]
-struct Status { streaming: bool, fs: Option<u32>, since_ms: Option<u128> }
+struct UrlBufferStatus { buffered_samples: usize, downloaded_bytes: u64, total_bytes: Option<u64>, underruns: u64 }
+
+#[cfg(feature = "web")]
+#[derive(Serialize)]
+struct Status { streaming: bool, fs: Option<u32>, since_ms: Option<u128>, url_buffer: Option<UrlBufferStatus> }
 
 #[cfg(feature = "web")]
 async fn status_handler(State(ctx): State<AppContext>) -> Json<Status> {
@@ -792,16 +1801,20 @@ async fn status_handler(State(ctx): State<AppContext>) -> Json<Status> {
 	let streaming = st.bg_task.is_some();
 	let fs = st.current_cfg.as_ref().map(|c| c.fs);
 	let since_ms = st.started_at.map(|t| t.elapsed().as_millis());
-	Json(Status { streaming, fs, since_ms })
+	let url_buffer = st.url_health.as_ref().map(|h| {
+		let h = h.lock().unwrap();
+		UrlBufferStatus { buffered_samples: h.buffered_samples, downloaded_bytes: h.downloaded_bytes, total_bytes: h.total_bytes, underruns: h.underruns }
+	});
+	Json(Status { streaming, fs, since_ms, url_buffer })
 }
 
 #[cfg(feature = "web")]
 #[derive(Serialize)]
-struct SavedConfig { fs: u32, pi_hex: String, ps: String, rt: String, pilot: f64, rds: f64, rds2: f64, enable_rds2: bool }
+struct SavedConfig { fs: u32, pi_hex: String, ps: String, rt: String, pilot: f64, rds: f64, rds2: f64, enable_rds2: bool, loudness_target: f64 }
 
 #[cfg(feature = "web")]
-async fn config_handler(State(ctx): State<AppContext>) -> Json<Option<SavedConfig>> {
-	if let Ok(row) = sqlx::query("SELECT fs, pi, ps, rt, pilot, rds, rds2, enable_rds2 FROM config WHERE id=1").fetch_one(&ctx.pool).await {
+async fn config_handler(State(ctx): State<AppContext>) -> ApiResponse {
+	if let Ok(row) = sqlx::query("SELECT fs, pi, ps, rt, pilot, rds, rds2, enable_rds2, loudness_target FROM config WHERE id=1").fetch_one(&ctx.pool).await {
 		let fs: i64 = row.get::<i64, _>("fs");
 		let pi: i64 = row.get::<i64, _>("pi");
 		let ps: String = row.try_get::<String, _>("ps").unwrap_or_default();
@@ -810,86 +1823,260 @@ async fn config_handler(State(ctx): State<AppContext>) -> Json<Option<SavedConfi
 		let rds: f64 = row.try_get::<f64, _>("rds").unwrap_or(0.03);
 		let rds2: f64 = row.try_get::<f64, _>("rds2").unwrap_or(0.01);
 		let enable_rds2: i64 = row.try_get::<i64, _>("enable_rds2").unwrap_or(1);
-		return Json(Some(SavedConfig { fs: fs as u32, pi_hex: format!("0x{:04X}", pi as u16), ps, rt, pilot, rds, rds2, enable_rds2: enable_rds2 != 0 }));
+		let loudness_target: f64 = row.try_get::<f64, _>("loudness_target").unwrap_or(DEFAULT_LOUDNESS_TARGET_LUFS);
+		return ApiResponse::success(Some(SavedConfig { fs: fs as u32, pi_hex: format!("0x{:04X}", pi as u16), ps, rt, pilot, rds, rds2, enable_rds2: enable_rds2 != 0, loudness_target }));
 	}
-	Json(None)
+	ApiResponse::success(Option::<SavedConfig>::None)
 }
 
 #[cfg(feature = "web")]
-async fn start_handler(State(ctx): State<AppContext>, mut multipart: Multipart) -> Result<Redirect, String> {
+async fn load_queue_items(pool: &SqlitePool) -> Vec<PlaylistItem> {
+	let _ = sqlx::query("CREATE TABLE IF NOT EXISTS queue_items (position INTEGER PRIMARY KEY, path TEXT NOT NULL, ps TEXT, rt TEXT)").execute(pool).await;
+	match sqlx::query("SELECT path, ps, rt FROM queue_items ORDER BY position").fetch_all(pool).await {
+		Ok(rows) => rows.into_iter().map(|row| PlaylistItem {
+			path: row.try_get::<String, _>("path").unwrap_or_default(),
+			ps: row.try_get::<String, _>("ps").ok().filter(|s: &String| !s.is_empty()),
+			rt: row.try_get::<String, _>("rt").ok().filter(|s: &String| !s.is_empty()),
+		}).collect(),
+		Err(_) => Vec::new(),
+	}
+}
+
+// Queue edits are infrequent and the list is small, so we persist by rewriting the whole
+// table rather than patching individual rows — the same "replace wholesale" approach the
+// single-row `config` table already uses.
+#[cfg(feature = "web")]
+async fn persist_queue_items(pool: &SqlitePool, items: &[PlaylistItem]) {
+	let _ = sqlx::query("CREATE TABLE IF NOT EXISTS queue_items (position INTEGER PRIMARY KEY, path TEXT NOT NULL, ps TEXT, rt TEXT)").execute(pool).await;
+	let _ = sqlx::query("DELETE FROM queue_items").execute(pool).await;
+	for (i, item) in items.iter().enumerate() {
+		let _ = sqlx::query("INSERT INTO queue_items (position, path, ps, rt) VALUES (?, ?, ?, ?)")
+			.bind(i as i64).bind(&item.path).bind(&item.ps).bind(&item.rt)
+			.execute(pool).await;
+	}
+}
+
+#[cfg(feature = "web")]
+#[derive(Serialize)]
+struct QueueStatus { items: Vec<PlaylistItem>, current_index: Option<usize>, mode: String }
+
+#[cfg(feature = "web")]
+async fn queue_status(ctx: &AppContext) -> QueueStatus {
+	let live = ctx.state.lock().unwrap().playlist.clone();
+	match live {
+		Some(shared) => {
+			let p = shared.lock().unwrap();
+			let mode = match p.mode { PlaylistMode::Loop => "loop", PlaylistMode::Stop => "stop" };
+			QueueStatus { items: p.items.clone(), current_index: Some(p.index), mode: mode.to_string() }
+		}
+		None => QueueStatus { items: load_queue_items(&ctx.pool).await, current_index: None, mode: "loop".to_string() },
+	}
+}
+
+#[cfg(feature = "web")]
+async fn queue_handler(State(ctx): State<AppContext>) -> Json<QueueStatus> { Json(queue_status(&ctx).await) }
+
+#[cfg(feature = "web")]
+#[derive(Deserialize)]
+struct QueueAddRequest { path: String, ps: Option<String>, rt: Option<String> }
+
+// The server has no auth in front of it, so `/queue/add` must not be able to make
+// `decode_audio_file` open an arbitrary local path: only accept files that already live
+// under `upload_dir` (i.e. came through the multipart upload flow), resolved by file name
+// and canonicalized so `..` components or symlinks can't escape the directory.
+#[cfg(feature = "web")]
+fn resolve_uploaded_path(upload_dir: &Path, requested: &str) -> Option<PathBuf> {
+	let name = Path::new(requested).file_name()?;
+	let candidate = upload_dir.join(name);
+	let canonical_dir = upload_dir.canonicalize().ok()?;
+	let canonical_candidate = candidate.canonicalize().ok()?;
+	if canonical_candidate.starts_with(&canonical_dir) { Some(canonical_candidate) } else { None }
+}
+
+#[cfg(feature = "web")]
+async fn queue_add_handler(State(ctx): State<AppContext>, Json(req): Json<QueueAddRequest>) -> Result<Json<QueueStatus>, ApiResponse> {
+	let resolved = resolve_uploaded_path(&ctx.upload_dir, &req.path)
+		.ok_or_else(|| ApiResponse::failure("path must refer to an uploaded file under the upload directory"))?;
+	let item = PlaylistItem { path: resolved.to_string_lossy().into_owned(), ps: req.ps.filter(|s| !s.is_empty()), rt: req.rt.filter(|s| !s.is_empty()) };
+	let mut items = load_queue_items(&ctx.pool).await;
+	items.push(item.clone());
+	persist_queue_items(&ctx.pool, &items).await;
+	if let Some(shared) = ctx.state.lock().unwrap().playlist.clone() { shared.lock().unwrap().items.push(item); }
+	Ok(Json(queue_status(&ctx).await))
+}
+
+#[cfg(feature = "web")]
+#[derive(Deserialize)]
+struct QueueRemoveRequest { index: usize }
+
+#[cfg(feature = "web")]
+async fn queue_remove_handler(State(ctx): State<AppContext>, Json(req): Json<QueueRemoveRequest>) -> Json<QueueStatus> {
+	let mut items = load_queue_items(&ctx.pool).await;
+	if req.index < items.len() { items.remove(req.index); }
+	persist_queue_items(&ctx.pool, &items).await;
+	if let Some(shared) = ctx.state.lock().unwrap().playlist.clone() {
+		let mut p = shared.lock().unwrap();
+		if req.index < p.items.len() {
+			p.items.remove(req.index);
+			if p.index >= p.items.len() { p.index = p.items.len().saturating_sub(1); }
+		}
+	}
+	Json(queue_status(&ctx).await)
+}
+
+#[cfg(feature = "web")]
+#[derive(Deserialize)]
+struct QueueReorderRequest { order: Vec<usize> }
+
+#[cfg(feature = "web")]
+async fn queue_reorder_handler(State(ctx): State<AppContext>, Json(req): Json<QueueReorderRequest>) -> Json<QueueStatus> {
+	let items = load_queue_items(&ctx.pool).await;
+	let reordered: Vec<PlaylistItem> = req.order.iter().filter_map(|&i| items.get(i).cloned()).collect();
+	persist_queue_items(&ctx.pool, &reordered).await;
+	if let Some(shared) = ctx.state.lock().unwrap().playlist.clone() {
+		let mut p = shared.lock().unwrap();
+		let playing_path = p.items.get(p.index).map(|i| i.path.clone());
+		p.items = reordered;
+		p.index = playing_path.and_then(|path| p.items.iter().position(|i| i.path == path)).unwrap_or(0);
+	}
+	Json(queue_status(&ctx).await)
+}
+
+#[cfg(feature = "web")]
+async fn skip_handler(State(ctx): State<AppContext>) -> Json<QueueStatus> {
+	if let Some(shared) = ctx.state.lock().unwrap().playlist.clone() {
+		shared.lock().unwrap().skip_requested = true;
+		emit_event(&Some(ctx.events.clone()), "playlist: skip requested");
+	}
+	Json(queue_status(&ctx).await)
+}
+
+#[cfg(feature = "web")]
+async fn start_handler(State(ctx): State<AppContext>, multipart: Multipart) -> ApiResponse {
+	match start_handler_inner(&ctx, multipart).await {
+		Ok(()) => ApiResponse::success(serde_json::json!({ "streaming": true })),
+		Err(resp) => resp,
+	}
+}
+
+#[cfg(feature = "web")]
+async fn start_handler_inner(ctx: &AppContext, mut multipart: Multipart) -> Result<(), ApiResponse> {
 	let upload_dir = ctx.upload_dir.clone();
-	fs::create_dir_all(&upload_dir).map_err(|e| e.to_string())?;
+	fs::create_dir_all(&upload_dir).map_err(|e| ApiResponse::failure(e.to_string()))?;
 	let mut fs_val: u32 = 192000;
 	let mut source = "tone".to_string();
 	let mut tone: f64 = 1000.0;
 	let mut duration: f64 = 60.0;
 	let mut device_index: Option<usize> = None;
+	let mut sink = "device".to_string();
+	let mut sink_addr: Option<String> = None;
+	let mut sink_transport = "udp".to_string();
+	let mut sink_quic_cert_sha256: Option<String> = None;
 	let mut audio_path: Option<PathBuf> = None;
+	let mut source_url: Option<String> = None;
+	let mut playlist_mode = "loop".to_string();
 	let mut pi: u16 = 0x1234;
 	let mut ps = "RADIO".to_string();
 	let mut rt = "Welcome to RADIO".to_string();
 	let mut pilot = 0.08f64; let mut rds = 0.03f64; let mut rds2 = 0.01f64; let mut enable_rds2 = true;
+	let mut loudness_target = DEFAULT_LOUDNESS_TARGET_LUFS;
 	let mut logo_bits: Option<Vec<u8>> = None;
-	while let Some(field) = multipart.next_field().await.map_err(|e| e.to_string())? {
+	while let Some(field) = multipart.next_field().await.map_err(|e| ApiResponse::failure(e.to_string()))? {
 		let name = field.name().unwrap_or("").to_string();
 		if name == "audio" {
 			if let Some(fname) = field.file_name().map(|s| s.to_string()) {
 				let p = upload_dir.join(format!("audio_{}_{}", chrono::Utc::now().timestamp(), fname));
-				let data = field.bytes().await.map_err(|e| e.to_string())?;
-				fs::write(&p, &data).map_err(|e| e.to_string())?;
+				let data = field.bytes().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+				fs::write(&p, &data).map_err(|e| ApiResponse::failure(e.to_string()))?;
 				audio_path = Some(p);
 			}
 		} else if name == "logo" {
 			if let Some(fname) = field.file_name().map(|s| s.to_string()) {
 				let p = upload_dir.join(format!("logo_{}_{}", chrono::Utc::now().timestamp(), fname));
-				let data = field.bytes().await.map_err(|e| e.to_string())?;
-				fs::write(&p, &data).map_err(|e| e.to_string())?;
-				logo_bits = Some(process_logo_to_bits(p.to_str().unwrap()).map_err(|e| e.to_string())?);
+				let data = field.bytes().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+				fs::write(&p, &data).map_err(|e| ApiResponse::failure(e.to_string()))?;
+				logo_bits = Some(process_logo_to_bits(p.to_str().unwrap()).map_err(|e| ApiResponse::failure(e.to_string()))?);
 			}
 		} else if name == "fs" {
-			fs_val = field.text().await.map_err(|e| e.to_string())?.parse().unwrap_or(192000);
+			fs_val = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(192000);
 		} else if name == "source" {
-			source = field.text().await.map_err(|e| e.to_string())?;
+			source = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
 		} else if name == "tone" {
-			tone = field.text().await.map_err(|e| e.to_string())?.parse().unwrap_or(1000.0);
+			tone = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(1000.0);
 		} else if name == "duration" {
-			duration = field.text().await.map_err(|e| e.to_string())?.parse().unwrap_or(60.0);
+			duration = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(60.0);
 		} else if name == "device" {
-			let v = field.text().await.map_err(|e| e.to_string())?;
+			let v = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
 			if !v.is_empty() { device_index = Some(v.parse().unwrap_or(0)); }
+		} else if name == "sink" {
+			sink = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+		} else if name == "sink_addr" {
+			let v = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+			if !v.is_empty() { sink_addr = Some(v); }
+		} else if name == "sink_transport" {
+			sink_transport = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+		} else if name == "sink_quic_cert_sha256" {
+			let v = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+			if !v.is_empty() { sink_quic_cert_sha256 = Some(v); }
+		} else if name == "source_url" {
+			let v = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
+			if !v.is_empty() { source_url = Some(v); }
+		} else if name == "playlist_mode" {
+			playlist_mode = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?;
 		} else if name == "pi" {
-			let v = field.text().await.map_err(|e| e.to_string())?; pi = u16::from_str_radix(v.trim_start_matches("0x"), 16).unwrap_or(0x1234);
-		} else if name == "ps" { ps = field.text().await.map_err(|e| e.to_string())?; }
-		else if name == "rt" { rt = field.text().await.map_err(|e| e.to_string())?; }
-		else if name == "pilot" { pilot = field.text().await.map_err(|e| e.to_string())?.parse().unwrap_or(0.08); }
-		else if name == "rds" { rds = field.text().await.map_err(|e| e.to_string())?.parse().unwrap_or(0.03); }
-		else if name == "rds2" { rds2 = field.text().await.map_err(|e| e.to_string())?.parse().unwrap_or(0.01); }
+			let v = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?; pi = u16::from_str_radix(v.trim_start_matches("0x"), 16).unwrap_or(0x1234);
+		} else if name == "ps" { ps = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?; }
+		else if name == "rt" { rt = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?; }
+		else if name == "pilot" { pilot = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(0.08); }
+		else if name == "rds" { rds = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(0.03); }
+		else if name == "rds2" { rds2 = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(0.01); }
 		else if name == "enable_rds2" { enable_rds2 = true; }
+		else if name == "loudness_target" { loudness_target = field.text().await.map_err(|e| ApiResponse::failure(e.to_string()))?.parse().unwrap_or(DEFAULT_LOUDNESS_TARGET_LUFS); }
 	}
-	let src_kind = if source == "file" { SourceKind::File { path: audio_path.and_then(|p| p.to_str().map(|s| s.to_string())).ok_or_else(|| "audio file missing".to_string())? } } else { SourceKind::Tone { freq: tone } };
-	let cfg = StreamConfig { fs: fs_val, device_index, source: src_kind, pi, ps: ps.clone(), rt: rt.clone(), pilot, rds, rds2, enable_rds2, logo_bits: logo_bits.clone() };
+	let src_kind = if source == "file" {
+		SourceKind::File { path: audio_path.and_then(|p| p.to_str().map(|s| s.to_string())).ok_or_else(|| ApiResponse::failure("audio file missing"))? }
+	} else if source == "url" {
+		SourceKind::Url { url: source_url.ok_or_else(|| ApiResponse::failure("source_url is required for a URL source"))? }
+	} else if source == "playlist" {
+		let mode = if playlist_mode == "stop" { PlaylistMode::Stop } else { PlaylistMode::Loop };
+		SourceKind::Playlist { items: load_queue_items(&ctx.pool).await, mode }
+	} else {
+		SourceKind::Tone { freq: tone }
+	};
+	let sink_kind = if sink == "network" {
+		let addr = sink_addr.ok_or_else(|| ApiResponse::failure("sink_addr is required for a network sink"))?;
+		let transport = NetworkTransport::parse(&sink_transport).ok_or_else(|| ApiResponse::failure("sink_transport must be udp, tcp, or quic"))?;
+		SinkKind::Network { addr, transport, quic_cert_sha256: sink_quic_cert_sha256 }
+	} else { SinkKind::Device { index: device_index } };
+	let cfg = StreamConfig { fs: fs_val, sink: sink_kind, source: src_kind, pi, ps: ps.clone(), rt: rt.clone(), pilot, rds, rds2, enable_rds2, logo_bits: logo_bits.clone(), loudness_target };
 	// Persist
-	let _ = sqlx::query("CREATE TABLE IF NOT EXISTS config (id INTEGER PRIMARY KEY, fs INTEGER, device_index INTEGER, pi INTEGER, ps TEXT, rt TEXT, pilot REAL, rds REAL, rds2 REAL, enable_rds2 INTEGER)").execute(&ctx.pool).await;
-	let _ = sqlx::query("INSERT OR REPLACE INTO config (id, fs, device_index, pi, ps, rt, pilot, rds, rds2, enable_rds2) VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-		.bind(fs_val as i64).bind(device_index.map(|v| v as i64)).bind(pi as i64).bind(ps).bind(rt).bind(pilot).bind(rds).bind(rds2).bind(if enable_rds2 {1} else {0})
+	let _ = sqlx::query("CREATE TABLE IF NOT EXISTS config (id INTEGER PRIMARY KEY, fs INTEGER, device_index INTEGER, pi INTEGER, ps TEXT, rt TEXT, pilot REAL, rds REAL, rds2 REAL, enable_rds2 INTEGER, loudness_target REAL)").execute(&ctx.pool).await;
+	let _ = sqlx::query("INSERT OR REPLACE INTO config (id, fs, device_index, pi, ps, rt, pilot, rds, rds2, enable_rds2, loudness_target) VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+		.bind(fs_val as i64).bind(device_index.map(|v| v as i64)).bind(pi as i64).bind(ps).bind(rt).bind(pilot).bind(rds).bind(rds2).bind(if enable_rds2 {1} else {0}).bind(loudness_target)
 		.execute(&ctx.pool).await;
 	// Stop any existing stream then start
 	stop_stream(ctx.state.clone()).await;
-	{ let mut s = ctx.state.lock().unwrap(); s.stop_flag.store(false, Ordering::SeqCst); s.bg_task = None; s.current_cfg = Some(cfg.clone()); s.started_at = Some(std::time::Instant::now()); }
-	start_stream(cfg, ctx.state.clone()).await.map_err(|e| e.to_string())?;
-	Ok(Redirect::to("/"))
+	{ let mut s = ctx.state.lock().unwrap(); s.stop_flag.store(false, Ordering::SeqCst); s.bg_task = None; s.current_cfg = Some(cfg.clone()); s.started_at = Some(std::time::Instant::now()); s.url_health = None; s.playlist = None; }
+	start_stream(cfg, ctx.state.clone()).await.map_err(|e| ApiResponse::fatal(e.to_string()))?;
+	Ok(())
 }
 
 #[cfg(feature = "web")]
-async fn stop_handler(ctx: axum::extract::State<AppContext>) -> Redirect { stop_stream(ctx.state.clone()).await; Redirect::to("/") }
+async fn stop_handler(State(ctx): State<AppContext>) -> ApiResponse {
+	stop_stream(ctx.state.clone()).await;
+	ApiResponse::success(serde_json::json!({ "streaming": false }))
+}
 
 #[cfg(feature = "web")]
 async fn run_server(port: u16, upload_dir: PathBuf) -> anyhow::Result<()> {
 	let db_path = upload_dir.join("db.sqlite");
 	fs::create_dir_all(&upload_dir)?;
 	let pool = SqlitePoolOptions::new().max_connections(5).connect(&format!("sqlite://{}", db_path.to_string_lossy())).await?;
-	let state = Arc::new(Mutex::new(RuntimeState::new()));
-	let ctx = AppContext { pool, state, upload_dir };
+	let (events_tx, _) = tokio::sync::broadcast::channel(256);
+	let mut runtime_state = RuntimeState::new();
+	runtime_state.events = Some(events_tx.clone());
+	let state = Arc::new(Mutex::new(runtime_state));
+	let ctx = AppContext { pool, state, upload_dir, events: events_tx };
 	let app = Router::new()
 		.route("/", get(index_handler))
 		.route("/devices", get(devices_handler))
@@ -897,6 +2084,12 @@ async fn run_server(port: u16, upload_dir: PathBuf) -> anyhow::Result<()> {
 		.route("/config", get(config_handler))
 		.route("/start", post(start_handler))
 		.route("/stop", get(stop_handler))
+		.route("/queue", get(queue_handler))
+		.route("/queue/add", post(queue_add_handler))
+		.route("/queue/remove", post(queue_remove_handler))
+		.route("/queue/reorder", post(queue_reorder_handler))
+		.route("/skip", post(skip_handler))
+		.route("/events", get(events_handler))
 		.with_state(ctx);
 	let addr = std::net::SocketAddr::from(([0,0,0,0], port));
 	axum::Server::bind(&addr).serve(app.into_make_service()).await?;
@@ -907,21 +2100,31 @@ async fn run_server(port: u16, upload_dir: PathBuf) -> anyhow::Result<()> {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let cli = Cli::parse();
 	match cli.command {
-		Commands::ToFile { fs, duration, tone, output, pi, ps, rt, level_mpx, pilot, rds, rds2, enable_rds2 } => {
+		Commands::ToFile { fs, duration, tone, output, pi, ps, rt, level_mpx, pilot, rds, rds2, enable_rds2, loudness_target } => {
 			let (left, right) = generate_tone_stereo(fs, duration, tone, level_mpx);
 			let mut gen = RdsGen::new(RdsConfig { pi, ps, rt });
 			let bits_needed = (duration * RDS_BITRATE * 1.1) as usize;
 			let rds_bits = gen.generate(bits_needed);
-			let mpx = make_mpx(&left, &right, fs, pilot, rds, rds2, &rds_bits, enable_rds2);
+			let mut processor = AudioProcessor::new(fs, loudness_target);
+			let mpx = make_mpx(&left, &right, fs, pilot, rds, rds2, &rds_bits, enable_rds2, &mut processor);
 			write_wav_mono(&output, fs, &mpx)?;
 			println!("Wrote {} samples to {}", mpx.len(), output);
 		}
 		Commands::Devices { fs: _ } => {
 			for d in list_output_devices() { println!("{} | {} | {}ch", d.index, d.name, d.channels); }
 		}
-		Commands::Play { fs, device_index, input_file, tone, pi, ps, rt, pilot, rds, rds2, enable_rds2 } => {
-			let source = if let Some(p) = input_file { SourceKind::File { path: p } } else { SourceKind::Tone { freq: tone } };
-			let cfg = StreamConfig { fs, device_index, source, pi, ps, rt, pilot, rds, rds2, enable_rds2, logo_bits: None };
+		Commands::Play { fs, device_index, sink, sink_addr, sink_transport, sink_quic_cert_sha256, input_file, url, playlist, playlist_mode, tone, pi, ps, rt, pilot, rds, rds2, enable_rds2, loudness_target } => {
+			let source = if let Some(list) = playlist {
+				let mode = if playlist_mode == "stop" { PlaylistMode::Stop } else { PlaylistMode::Loop };
+				let items = list.split(',').map(|p| PlaylistItem { path: p.trim().to_string(), ps: None, rt: None }).collect();
+				SourceKind::Playlist { items, mode }
+			} else if let Some(u) = url { SourceKind::Url { url: u } } else if let Some(p) = input_file { SourceKind::File { path: p } } else { SourceKind::Tone { freq: tone } };
+			let sink_kind = if sink == "network" {
+				let addr = sink_addr.ok_or("--sink-addr is required for --sink=network")?;
+				let transport = NetworkTransport::parse(&sink_transport).ok_or("--sink-transport must be udp, tcp, or quic")?;
+				SinkKind::Network { addr, transport, quic_cert_sha256: sink_quic_cert_sha256 }
+			} else { SinkKind::Device { index: device_index } };
+			let cfg = StreamConfig { fs, sink: sink_kind, source, pi, ps, rt, pilot, rds, rds2, enable_rds2, logo_bits: None, loudness_target };
 			let state = Arc::new(Mutex::new(RuntimeState::new()));
 			start_stream(cfg, state.clone()).await?;
 			println!("Playing... Ctrl+C to stop");